@@ -4,13 +4,85 @@
 //! # DEPRECATED: Ascon permutation
 //!
 //! Use the `ascon` crate instead.
+//!
+//! ## Panics
+//!
+//! Besides [`clear`], the following re-exported items can panic:
+//!
+//! * [`pad`]`(n)` requires `n` to be in `0..=7`. Other values panic in debug
+//!   builds and give an unspecified result in release builds.
+//! * [`State::permute_n`] panics (in debug mode) if `rounds` is larger than 12.
+//! * Indexing a [`State`] panics if the index is not in `0..5`.
 
 #![no_std]
 
 pub use ascon::*;
 
 /// Clear bytes from a 64 bit word.
+///
+/// Clears the `n` most significant bytes of `word`. `n` must be in `1..=8`;
+/// other values panic in debug builds and give an unspecified result in
+/// release builds.
+///
+/// ```
+/// use ascon_core::clear;
+///
+/// assert_eq!(clear(0x0123456789abcdef, 1), 0x0023456789abcdef);
+/// assert_eq!(clear(0x0123456789abcdef, 8), 0);
+/// ```
 #[inline(always)]
 pub const fn clear(word: u64, n: usize) -> u64 {
     word & (0x00ffffffffffffff >> (n * 8 - 8))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_1to8() {
+        assert_eq!(clear(0x0123456789abcdef, 1), 0x0023456789abcdef);
+        assert_eq!(clear(0x0123456789abcdef, 2), 0x0000456789abcdef);
+        assert_eq!(clear(0x0123456789abcdef, 3), 0x0000006789abcdef);
+        assert_eq!(clear(0x0123456789abcdef, 4), 0x0000000089abcdef);
+        assert_eq!(clear(0x0123456789abcdef, 5), 0x0000000000abcdef);
+        assert_eq!(clear(0x0123456789abcdef, 6), 0x000000000000cdef);
+        assert_eq!(clear(0x0123456789abcdef, 7), 0x00000000000000ef);
+        assert_eq!(clear(0x0123456789abcdef, 8), 0x0000000000000000);
+    }
+
+    #[test]
+    fn state_convert_bytes() {
+        let mut bytes = [0u8; 40];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        let state = State::from(&bytes);
+        assert_eq!(state[0], 0x0001020304050607);
+        assert_eq!(state[4], 0x2021222324252627);
+        assert_eq!(state.as_bytes(), bytes);
+    }
+
+    #[test]
+    fn state_permute_12() {
+        let mut bytes = [0u8; 40];
+        for (dst, src) in bytes.chunks_exact_mut(8).zip([
+            0x0123456789abcdef,
+            0xef0123456789abcd,
+            0xcdef0123456789ab,
+            0xabcdef0123456789,
+            0x89abcdef01234567u64,
+        ]) {
+            dst.copy_from_slice(&src.to_be_bytes());
+        }
+
+        let mut state = State::from(&bytes);
+        state.permute_12();
+        assert_eq!(state[0], 0x206416dfc624bb14);
+        assert_eq!(state[1], 0x1b0c47a601058aab);
+        assert_eq!(state[2], 0x8934cfc93814cddd);
+        assert_eq!(state[3], 0xa9738d287a748e4b);
+        assert_eq!(state[4], 0xddd934f058afc7e1);
+    }
+}